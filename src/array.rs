@@ -1,24 +1,27 @@
 #![cfg(feature = "array")]
 
-use ndarray::OwnedRepr;
+use ndarray::{Array1, Array2, OwnedRepr};
 use ndarray_linalg::layout::MatrixLayout;
 use ndarray_linalg::svd::SVDInto;
 use ndarray_linalg::{convert, types};
-use typenum::type_operators::Cmp;
-use typenum::{Greater, Unsigned, U2, U3};
+use typenum::Unsigned;
 
-use crate::query::{Plane, Unit};
+use crate::query::{Line, Plane, Unit};
 use crate::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
 use crate::{FromItems, IntoItems};
 
+/// Below this fraction of the largest singular value, the gap between two
+/// singular values is considered zero and the fit they would otherwise
+/// distinguish is ambiguous. Relative to `sigma[0]` rather than an absolute
+/// gap, so the test is invariant to the scale of the input points.
+const TOLERANCE: f64 = 1e-6;
+
 impl<S> Plane<S>
 where
     S: EuclideanSpace + FiniteDimensional,
-    <S as FiniteDimensional>::N: Cmp<U2, Output = Greater>,
 {
     pub fn from_points<I>(points: I) -> Option<Self>
     where
-        S: FiniteDimensional<N = U3>,
         Scalar<S>: types::Scalar,
         Vector<S>: FromItems + IntoItems,
         I: AsRef<[S]> + Clone + IntoIterator<Item = S>,
@@ -27,18 +30,93 @@ where
     }
 }
 
-// TODO: Handle edge cases and improve error handling.
+impl<S> Line<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+{
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: types::Scalar,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]> + Clone + IntoIterator<Item = S>,
+    {
+        svd_ev_line(points)
+    }
+}
+
+/// Total-least-squares (PCA) best-fit hyperplane.
+///
+/// Returns `None` if there are fewer points than dimensions, or if the fit
+/// is ambiguous: the two smallest singular values are equal within
+/// `TOLERANCE` of the largest, so no single direction is distinguished as
+/// the normal (e.g. the input is collinear or otherwise degenerate).
 pub fn svd_ev_plane<S, I>(points: I) -> Option<Plane<S>>
 where
-    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: types::Scalar,
+    Vector<S>: FromItems + IntoItems,
+    I: AsRef<[S]> + Clone + IntoIterator<Item = S>,
+{
+    let (centroid, sigma, vt) = fit(points)?;
+    let d = sigma.len();
+    if d < 2 || (sigma[d - 2] - sigma[d - 1]).abs() <= TOLERANCE * sigma[0] {
+        return None;
+    }
+    // `sigma` (and the rows of `vt`) are ordered by descending singular
+    // value, so the smallest corresponds to the last row.
+    let normal = Vector::<S>::from_items(vt.row(d - 1).iter().cloned())?;
+    Some(Plane {
+        origin: centroid,
+        normal: Unit::try_from_inner(normal)?,
+    })
+}
+
+/// Total-least-squares (PCA) best-fit line.
+///
+/// Returns `None` if there are fewer points than dimensions, or if the fit
+/// is ambiguous: the two largest singular values are equal within
+/// `TOLERANCE` of the largest, so no single direction is distinguished as
+/// the line.
+pub fn svd_ev_line<S, I>(points: I) -> Option<Line<S>>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: types::Scalar,
+    Vector<S>: FromItems + IntoItems,
+    I: AsRef<[S]> + Clone + IntoIterator<Item = S>,
+{
+    let (origin, sigma, vt) = fit(points)?;
+    if sigma.len() < 2 || (sigma[0] - sigma[1]).abs() <= TOLERANCE * sigma[0] {
+        return None;
+    }
+    let direction = Vector::<S>::from_items(vt.row(0).iter().cloned())?;
+    Some(Line {
+        origin,
+        direction: Unit::try_from_inner(direction)?,
+    })
+}
+
+/// Centers `points` on their centroid and computes the SVD of the
+/// resulting `n × d` matrix, where `d` is the ambient dimension.
+///
+/// Returns the centroid alongside the singular values (descending) and
+/// `V^T`, whose rows are the corresponding right singular vectors.
+fn fit<S, I>(
+    points: I,
+) -> Option<(S, Array1<<Scalar<S> as types::Scalar>::Real>, Array2<Scalar<S>>)>
+where
+    S: EuclideanSpace + FiniteDimensional,
     Scalar<S>: types::Scalar,
     Vector<S>: FromItems + IntoItems,
     I: AsRef<[S]> + Clone + IntoIterator<Item = S>,
 {
     let n = points.as_ref().len();
+    let d = <S as FiniteDimensional>::N::USIZE;
+    if n < d {
+        return None;
+    }
     let centroid = EuclideanSpace::centroid(points.clone())?;
     let m = convert::into_matrix::<_, OwnedRepr<_>>(
-        MatrixLayout::F((n as i32, <S as FiniteDimensional>::N::USIZE as i32)),
+        MatrixLayout::C((n as i32, d as i32)),
         points
             .into_iter()
             .map(|point| point - centroid)
@@ -46,27 +124,8 @@ where
             .collect(),
     )
     .ok()?;
-    // TODO: Fails at runtime if `V^T` is not requested.
-    if let Ok((Some(u), sigma, _)) = m.svd_into(true, true) {
-        let i = sigma
-            .iter()
-            .enumerate()
-            .min_by(|(_, v1), (_, v2)| v1.partial_cmp(v2).unwrap())?
-            .0;
-        if i < u.cols() {
-            let normal = Vector::<S>::from_items(u.column(i).into_iter().cloned())?;
-            Some(Plane {
-                origin: centroid,
-                normal: Unit::try_from_inner(normal)?,
-            })
-        }
-        else {
-            None
-        }
-    }
-    else {
-        None
-    }
+    let (_, sigma, vt) = m.svd_into(false, true).ok()?;
+    Some((centroid, sigma, vt?))
 }
 
 #[cfg(test)]
@@ -74,19 +133,21 @@ mod tests {
     use nalgebra::Point3;
 
     use crate::query::Plane;
-    use crate::space::{EuclideanSpace, Vector};
+    use crate::space::{ApproxEq, EuclideanSpace, Vector};
 
     type E3 = Point3<f64>;
 
     #[test]
     fn svd_ev_plane_e3() {
-        // Form a determined plane from a triangle.
+        // Form a determined plane from a triangle. Exact `assert_eq!` is
+        // too strict for an SVD result, so compare with `approx_eq`
+        // instead.
         let plane = Plane::<E3>::from_points(vec![
             EuclideanSpace::from_xyz(1.0, 0.0, 0.0),
             EuclideanSpace::from_xyz(0.5, 0.5, 0.0),
             EuclideanSpace::from_xyz(0.0, 1.0, 0.0),
         ])
         .unwrap();
-        assert_eq!(Vector::<E3>::z(), plane.normal.get().clone());
+        assert!(Vector::<E3>::z().approx_eq(plane.normal.get()));
     }
 }
\ No newline at end of file
@@ -3,6 +3,7 @@ pub mod ops;
 pub mod partition;
 pub mod query;
 pub mod space;
+pub mod voxel;
 
 // Foreign implementation modules. These are empty unless the corresponding
 // geometry features are enabled.
@@ -18,6 +19,7 @@ use num::{self, Num, NumCast, One, Zero};
 
 pub mod prelude {
     pub use crate::query::Intersection as _;
+    pub use crate::space::ApproxEq as _;
     pub use crate::Lattice as _;
 }
 
@@ -0,0 +1,287 @@
+//! Homogeneous affine/projective transforms.
+//!
+//! `Transform` is backed by a dense `(N+1) × (N+1)` matrix and is generic
+//! over the ambient dimension via `FiniteDimensional`, so it composes with
+//! any backend's points, vectors, and planes through the `space`/`query`
+//! abstractions rather than a specific cgmath/nalgebra/mint matrix type.
+
+use std::ops::Mul;
+
+use num::{Float, One, Zero};
+use typenum::{Unsigned, U3};
+
+use crate::query::{Plane, Unit};
+use crate::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use crate::{FromItems, IntoItems};
+
+/// A homogeneous transform over an `(N+1) × (N+1)` matrix, stored
+/// row-major.
+#[derive(Clone, Debug)]
+pub struct Transform<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+{
+    dimension: usize,
+    elements: Vec<Scalar<S>>,
+}
+
+impl<S> Transform<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: Float,
+{
+    fn get(&self, row: usize, column: usize) -> Scalar<S> {
+        self.elements[row * self.dimension + column]
+    }
+
+    fn set(&mut self, row: usize, column: usize, value: Scalar<S>) {
+        self.elements[row * self.dimension + column] = value;
+    }
+
+    /// The identity transform.
+    pub fn identity() -> Self {
+        let dimension = <S as FiniteDimensional>::N::USIZE + 1;
+        let mut elements = vec![Scalar::<S>::zero(); dimension * dimension];
+        for i in 0..dimension {
+            elements[i * dimension + i] = Scalar::<S>::one();
+        }
+        Transform { dimension, elements }
+    }
+
+    /// A transform that translates by `vector`.
+    pub fn from_translation(vector: Vector<S>) -> Self
+    where
+        Vector<S>: IntoItems,
+    {
+        let mut transform = Self::identity();
+        let n = transform.dimension - 1;
+        for (i, component) in vector.into_items().into_iter().enumerate() {
+            transform.set(i, n, component);
+        }
+        transform
+    }
+
+    /// A transform that scales componentwise by `vector`.
+    pub fn from_scale(vector: Vector<S>) -> Self
+    where
+        Vector<S>: IntoItems,
+    {
+        let mut transform = Self::identity();
+        for (i, component) in vector.into_items().into_iter().enumerate() {
+            transform.set(i, i, component);
+        }
+        transform
+    }
+
+    /// A transform that rotates by `angle` (in radians) about `axis`, via
+    /// the Rodrigues rotation formula. Defined only in three dimensions.
+    pub fn from_rotation(axis: Unit<Vector<S>>, angle: Scalar<S>) -> Self
+    where
+        S: FiniteDimensional<N = U3>,
+        Vector<S>: IntoItems,
+    {
+        let mut components = axis.get().clone().into_items().into_iter();
+        let x = components.next().unwrap();
+        let y = components.next().unwrap();
+        let z = components.next().unwrap();
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let t = Scalar::<S>::one() - cos;
+
+        let mut transform = Self::identity();
+        transform.set(0, 0, t * x * x + cos);
+        transform.set(0, 1, t * x * y - sin * z);
+        transform.set(0, 2, t * x * z + sin * y);
+        transform.set(1, 0, t * x * y + sin * z);
+        transform.set(1, 1, t * y * y + cos);
+        transform.set(1, 2, t * y * z - sin * x);
+        transform.set(2, 0, t * x * z - sin * y);
+        transform.set(2, 1, t * y * z + sin * x);
+        transform.set(2, 2, t * z * z + cos);
+        transform
+    }
+
+    /// Applies this transform to a point, appending a homogeneous `1` and
+    /// performing the perspective divide by `w`.
+    pub fn transform_point(&self, point: S) -> S
+    where
+        Vector<S>: FromItems + IntoItems,
+    {
+        let n = self.dimension - 1;
+        let mut homogeneous: Vec<_> = (point - S::origin()).into_items().into_iter().collect();
+        homogeneous.push(Scalar::<S>::one());
+
+        let mut out = vec![Scalar::<S>::zero(); n + 1];
+        for (row, slot) in out.iter_mut().enumerate() {
+            for (column, &value) in homogeneous.iter().enumerate() {
+                *slot = *slot + self.get(row, column) * value;
+            }
+        }
+        let w = out[n];
+        let vector = Vector::<S>::from_items(out[..n].iter().map(|&value| value / w)).unwrap();
+        S::origin() + vector
+    }
+
+    /// Applies this transform to a vector, appending a homogeneous `0` and
+    /// skipping the perspective divide.
+    pub fn transform_vector(&self, vector: Vector<S>) -> Vector<S>
+    where
+        Vector<S>: FromItems + IntoItems,
+    {
+        let n = self.dimension - 1;
+        let mut homogeneous: Vec<_> = vector.into_items().into_iter().collect();
+        homogeneous.push(Scalar::<S>::zero());
+
+        let mut out = vec![Scalar::<S>::zero(); n];
+        for (row, slot) in out.iter_mut().enumerate() {
+            for (column, &value) in homogeneous.iter().enumerate() {
+                *slot = *slot + self.get(row, column) * value;
+            }
+        }
+        Vector::<S>::from_items(out).unwrap()
+    }
+
+    /// Applies this transform to a plane.
+    ///
+    /// The normal is mapped by the inverse-transpose of the transform's
+    /// linear part and the plane offset is recomputed from the
+    /// transformed origin, so that half-space tests against the result
+    /// agree with transforming the tested points instead.
+    pub fn transform_plane(&self, plane: &Plane<S>) -> Option<Plane<S>>
+    where
+        Vector<S>: InnerSpace + FromItems + IntoItems,
+    {
+        let n = self.dimension - 1;
+        let inverse_transpose = self.linear_inverse_transpose()?;
+        let components: Vec<_> = plane.normal.get().clone().into_items().into_iter().collect();
+
+        let mut transformed = vec![Scalar::<S>::zero(); n];
+        for (row, slot) in transformed.iter_mut().enumerate() {
+            for (column, &value) in components.iter().enumerate() {
+                *slot = *slot + inverse_transpose[row * n + column] * value;
+            }
+        }
+
+        let normal = Unit::try_from_inner(Vector::<S>::from_items(transformed)?)?;
+        let origin = self.transform_point(plane.origin);
+        Some(Plane { origin, normal })
+    }
+
+    /// Computes the inverse-transpose of this transform's upper-left
+    /// `n × n` (linear) submatrix via Gauss-Jordan elimination with
+    /// partial pivoting, returned flattened row-major.
+    ///
+    /// Returns `None` if the linear part is singular (including when
+    /// pivoting cannot find a usably non-zero pivot).
+    fn linear_inverse_transpose(&self) -> Option<Vec<Scalar<S>>> {
+        let n = self.dimension - 1;
+        let mut a: Vec<Vec<_>> = (0..n)
+            .map(|row| (0..n).map(|column| self.get(row, column)).collect())
+            .collect();
+        let mut inverse: Vec<Vec<_>> = (0..n)
+            .map(|row| {
+                (0..n)
+                    .map(|column| {
+                        if row == column {
+                            Scalar::<S>::one()
+                        }
+                        else {
+                            Scalar::<S>::zero()
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for pivot in 0..n {
+            let best = (pivot..n).max_by(|&i, &j| {
+                a[i][pivot].abs().partial_cmp(&a[j][pivot].abs()).unwrap()
+            })?;
+            if a[best][pivot].abs() <= Scalar::<S>::epsilon() {
+                return None;
+            }
+            a.swap(pivot, best);
+            inverse.swap(pivot, best);
+
+            let scale = a[pivot][pivot];
+            for column in 0..n {
+                a[pivot][column] = a[pivot][column] / scale;
+                inverse[pivot][column] = inverse[pivot][column] / scale;
+            }
+            for row in 0..n {
+                if row == pivot {
+                    continue;
+                }
+                let factor = a[row][pivot];
+                for column in 0..n {
+                    a[row][column] = a[row][column] - factor * a[pivot][column];
+                    inverse[row][column] = inverse[row][column] - factor * inverse[pivot][column];
+                }
+            }
+        }
+
+        let mut transpose = vec![Scalar::<S>::zero(); n * n];
+        for row in 0..n {
+            for column in 0..n {
+                transpose[row * n + column] = inverse[column][row];
+            }
+        }
+        Some(transpose)
+    }
+}
+
+impl<S> Mul for Transform<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: Float,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let n = self.dimension;
+        let mut elements = vec![Scalar::<S>::zero(); n * n];
+        for row in 0..n {
+            for column in 0..n {
+                let mut sum = Scalar::<S>::zero();
+                for k in 0..n {
+                    sum = sum + self.get(row, k) * rhs.get(k, column);
+                }
+                elements[row * n + column] = sum;
+            }
+        }
+        Transform { dimension: n, elements }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::ops::Transform;
+    use crate::query::{Plane, Unit};
+    use crate::space::{ApproxEq, Basis, EuclideanSpace, Vector};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn transform_plane_rotation() {
+        // A 90 degree rotation about z must carry the x-normal plane
+        // through x=1 onto the y-normal plane through y=1, exercising the
+        // inverse-transpose normal mapping even where the linear part has
+        // zeros on its diagonal.
+        let plane = Plane {
+            origin: EuclideanSpace::from_xyz(1.0, 0.0, 0.0),
+            normal: Unit::try_from_inner(Vector::<E3>::x()).unwrap(),
+        };
+        let rotation = Transform::from_rotation(
+            Unit::try_from_inner(Vector::<E3>::z()).unwrap(),
+            std::f64::consts::FRAC_PI_2,
+        );
+        let rotated = rotation.transform_plane(&plane).unwrap();
+
+        let expected = Plane {
+            origin: EuclideanSpace::from_xyz(0.0, 1.0, 0.0),
+            normal: Unit::try_from_inner(Vector::<E3>::y()).unwrap(),
+        };
+        assert!(rotated.approx_eq(&expected));
+    }
+}
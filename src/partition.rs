@@ -0,0 +1,325 @@
+//! Binary space partitioning over convex polygons.
+//!
+//! A `Bsp` recursively splits a set of convex polygons by their own
+//! supporting planes, producing a tree that can be walked in exact
+//! back-to-front (painter's algorithm) or front-to-back order from any eye
+//! point, and queried for CSG-style clipping. This gives correct
+//! transparency ordering without pulling in a separate plane-split crate.
+
+use num::NumCast;
+
+use crate::query::{Plane, Unit};
+use crate::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+
+/// Tolerance within which a point is considered to lie on a plane.
+const EPSILON: f64 = 1e-8;
+
+/// A convex polygon, represented as an ordered list of coplanar vertices.
+#[derive(Clone, Debug)]
+pub struct Polygon<S> {
+    pub vertices: Vec<S>,
+}
+
+impl<S> Polygon<S> {
+    pub fn new(vertices: Vec<S>) -> Self {
+        Polygon { vertices }
+    }
+}
+
+impl<S> Polygon<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: num::Float,
+{
+    /// Computes this polygon's supporting plane from its first three
+    /// vertices.
+    ///
+    /// Returns `None` if the polygon is degenerate: it has fewer than
+    /// three vertices, or its first three vertices are collinear.
+    pub fn plane(&self) -> Option<Plane<S>> {
+        let a = *self.vertices.get(0)?;
+        let b = *self.vertices.get(1)?;
+        let c = *self.vertices.get(2)?;
+        let normal = Unit::try_from_inner((b - a).cross(c - a))?;
+        Some(Plane { origin: a, normal })
+    }
+}
+
+/// A heuristic for choosing a node's splitting plane among the polygons at
+/// that level of the tree.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Heuristic {
+    /// Always splits on the first remaining polygon. Cheap, but can
+    /// produce many more splits than necessary.
+    First,
+    /// Splits on the polygon whose plane straddles the fewest of its
+    /// siblings, reducing (though not necessarily minimizing) the total
+    /// number of splits in the tree.
+    MinimizeSplits,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Side {
+    Front,
+    Back,
+    Coplanar,
+}
+
+fn classify(distance: f64) -> Side {
+    if distance >= EPSILON {
+        Side::Front
+    }
+    else if distance <= -EPSILON {
+        Side::Back
+    }
+    else {
+        Side::Coplanar
+    }
+}
+
+/// The order in which `Bsp::traverse` yields polygons relative to the eye.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Order {
+    BackToFront,
+    FrontToBack,
+}
+
+struct Node<S>
+where
+    S: EuclideanSpace,
+{
+    plane: Plane<S>,
+    coplanar: Vec<Polygon<S>>,
+    front: Option<Box<Node<S>>>,
+    back: Option<Box<Node<S>>>,
+}
+
+/// A binary space partitioning of a set of convex polygons.
+///
+/// See the module documentation for the partitioning and traversal
+/// algorithms.
+pub struct Bsp<S>
+where
+    S: EuclideanSpace,
+{
+    root: Option<Node<S>>,
+}
+
+impl<S> Bsp<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: num::Float,
+{
+    /// Builds a BSP tree from a set of convex polygons, splitting on the
+    /// polygon chosen by `Heuristic::MinimizeSplits` at each node.
+    pub fn new(polygons: Vec<Polygon<S>>) -> Self {
+        Self::with_heuristic(polygons, Heuristic::MinimizeSplits)
+    }
+
+    pub fn with_heuristic(polygons: Vec<Polygon<S>>, heuristic: Heuristic) -> Self {
+        Bsp {
+            root: Self::build(polygons, heuristic),
+        }
+    }
+
+    fn build(mut polygons: Vec<Polygon<S>>, heuristic: Heuristic) -> Option<Node<S>> {
+        if polygons.is_empty() {
+            return None;
+        }
+        let index = match heuristic {
+            Heuristic::First => 0,
+            Heuristic::MinimizeSplits => Self::select_splitter(&polygons),
+        };
+        let splitter = polygons.remove(index);
+        let plane = splitter.plane()?;
+
+        let mut coplanar = vec![splitter];
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for polygon in polygons {
+            Self::sort(&plane, polygon, &mut coplanar, &mut front, &mut back);
+        }
+
+        Some(Node {
+            plane,
+            coplanar,
+            front: Self::build(front, heuristic).map(Box::new),
+            back: Self::build(back, heuristic).map(Box::new),
+        })
+    }
+
+    /// Chooses the index, within `polygons`, of the polygon whose
+    /// supporting plane straddles the fewest of the others.
+    fn select_splitter(polygons: &[Polygon<S>]) -> usize {
+        polygons
+            .iter()
+            .enumerate()
+            .min_by_key(|(i, candidate)| match candidate.plane() {
+                Some(plane) => polygons
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| j != i)
+                    .filter(|(_, other)| Self::straddles(&plane, other))
+                    .count(),
+                None => usize::max_value(),
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    fn straddles(plane: &Plane<S>, polygon: &Polygon<S>) -> bool {
+        let mut sides = polygon
+            .vertices
+            .iter()
+            .map(|&vertex| classify(Self::distance(plane, vertex)))
+            .filter(|side| *side != Side::Coplanar);
+        match sides.next() {
+            Some(first) => sides.any(|side| side != first),
+            None => false,
+        }
+    }
+
+    fn distance(plane: &Plane<S>, point: S) -> f64 {
+        let d = plane.normal.get().dot(point - plane.origin);
+        <f64 as NumCast>::from(d).unwrap()
+    }
+
+    fn sort(
+        plane: &Plane<S>,
+        polygon: Polygon<S>,
+        coplanar: &mut Vec<Polygon<S>>,
+        front: &mut Vec<Polygon<S>>,
+        back: &mut Vec<Polygon<S>>,
+    ) {
+        let distances: Vec<_> = polygon
+            .vertices
+            .iter()
+            .map(|&vertex| Self::distance(plane, vertex))
+            .collect();
+        let sides: Vec<_> = distances.iter().cloned().map(classify).collect();
+
+        if sides.iter().all(|side| *side != Side::Back) {
+            if sides.iter().all(|side| *side == Side::Coplanar) {
+                coplanar.push(polygon);
+            }
+            else {
+                front.push(polygon);
+            }
+        }
+        else if sides.iter().all(|side| *side != Side::Front) {
+            back.push(polygon);
+        }
+        else {
+            let (f, b) = Self::split(&polygon, &distances);
+            front.push(f);
+            back.push(b);
+        }
+    }
+
+    /// Splits a straddling polygon into a front and a back sub-polygon,
+    /// walking its edges and inserting an intersection vertex wherever
+    /// consecutive vertices fall on opposite sides of `plane`.
+    fn split(polygon: &Polygon<S>, distances: &[f64]) -> (Polygon<S>, Polygon<S>) {
+        let n = polygon.vertices.len();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+
+        for i in 0..n {
+            let j = (i + 1) % n;
+            let (vi, vj) = (polygon.vertices[i], polygon.vertices[j]);
+            let (di, dj) = (distances[i], distances[j]);
+
+            if di >= -EPSILON {
+                front.push(vi);
+            }
+            if di <= EPSILON {
+                back.push(vi);
+            }
+            if (di > EPSILON && dj < -EPSILON) || (di < -EPSILON && dj > EPSILON) {
+                let t = <Scalar<S> as NumCast>::from(di / (di - dj)).unwrap();
+                let point = vi + (vj - vi) * t;
+                front.push(point);
+                back.push(point);
+            }
+        }
+        (Polygon::new(front), Polygon::new(back))
+    }
+
+    /// Visits this tree's polygons in back-to-front or front-to-back order
+    /// relative to `eye`, per the painter's algorithm.
+    pub fn traverse(&self, eye: S, order: Order) -> impl Iterator<Item = &Polygon<S>> {
+        let mut polygons = Vec::new();
+        if let Some(root) = &self.root {
+            Self::traverse_node(root, eye, order, &mut polygons);
+        }
+        polygons.into_iter()
+    }
+
+    fn traverse_node<'a>(node: &'a Node<S>, eye: S, order: Order, out: &mut Vec<&'a Polygon<S>>) {
+        let in_front = Self::distance(&node.plane, eye) >= 0.0;
+        let (near, far) = if in_front {
+            (&node.front, &node.back)
+        }
+        else {
+            (&node.back, &node.front)
+        };
+        let (first, second) = match order {
+            Order::BackToFront => (far, near),
+            Order::FrontToBack => (near, far),
+        };
+        if let Some(first) = first {
+            Self::traverse_node(first, eye, order, out);
+        }
+        out.extend(node.coplanar.iter());
+        if let Some(second) = second {
+            Self::traverse_node(second, eye, order, out);
+        }
+    }
+
+    /// Clips `polygon` against this tree, returning the sub-polygons of it
+    /// that lie outside (in front of) the partitioned solid.
+    pub fn clip(&self, polygon: Polygon<S>) -> Vec<Polygon<S>> {
+        Self::clip_front(self.root.as_ref(), polygon)
+    }
+
+    /// Clips `polygon` against the subtree reached via a front child: a
+    /// missing node is open space outside the solid, so `polygon` is kept.
+    fn clip_front(node: Option<&Node<S>>, polygon: Polygon<S>) -> Vec<Polygon<S>> {
+        match node {
+            Some(node) => Self::clip_at(node, polygon),
+            None => vec![polygon],
+        }
+    }
+
+    /// Clips `polygon` against the subtree reached via a back child: a
+    /// missing node is solid interior, so `polygon` is dropped.
+    fn clip_back(node: Option<&Node<S>>, polygon: Polygon<S>) -> Vec<Polygon<S>> {
+        match node {
+            Some(node) => Self::clip_at(node, polygon),
+            None => Vec::new(),
+        }
+    }
+
+    fn clip_at(node: &Node<S>, polygon: Polygon<S>) -> Vec<Polygon<S>> {
+        let distances: Vec<_> = polygon
+            .vertices
+            .iter()
+            .map(|&vertex| Self::distance(&node.plane, vertex))
+            .collect();
+
+        if distances.iter().all(|d| *d >= -EPSILON) {
+            Self::clip_front(node.front.as_deref(), polygon)
+        }
+        else if distances.iter().all(|d| *d <= EPSILON) {
+            Self::clip_back(node.back.as_deref(), polygon)
+        }
+        else {
+            let (front, back) = Self::split(&polygon, &distances);
+            let mut out = Self::clip_front(node.front.as_deref(), front);
+            out.extend(Self::clip_back(node.back.as_deref(), back));
+            out
+        }
+    }
+}
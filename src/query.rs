@@ -0,0 +1,314 @@
+//! Geometric primitives (`Plane`, `Line`, `Ray`, `Segment`) and the queries
+//! (`Intersection`, ...) defined over them.
+
+use num::{Float, One, Zero};
+use typenum::U3;
+
+use crate::space::{ApproxEq, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+
+/// A vector known to have unit magnitude.
+///
+/// `Unit` performs no ongoing validation; `try_from_inner` normalizes its
+/// input once at construction time and rejects vectors too close to zero
+/// to normalize meaningfully.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Unit<T> {
+    inner: T,
+}
+
+impl<T> Unit<T>
+where
+    T: InnerSpace,
+    T::Scalar: Float,
+{
+    pub fn try_from_inner(inner: T) -> Option<Self> {
+        let magnitude = inner.magnitude();
+        if magnitude <= T::Scalar::epsilon() {
+            None
+        }
+        else {
+            Some(Unit {
+                inner: inner * (T::Scalar::one() / magnitude),
+            })
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// A plane embedded in a Euclidean space, represented as a point on the
+/// plane and a unit normal.
+#[derive(Clone, Copy, Debug)]
+pub struct Plane<S>
+where
+    S: EuclideanSpace,
+{
+    pub origin: S,
+    pub normal: Unit<Vector<S>>,
+}
+
+/// An infinite line through a point in some direction.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<S>
+where
+    S: EuclideanSpace,
+{
+    pub origin: S,
+    pub direction: Unit<Vector<S>>,
+}
+
+/// A half-infinite line extending from `origin` in `direction`.
+#[derive(Clone, Copy, Debug)]
+pub struct Ray<S>
+where
+    S: EuclideanSpace,
+{
+    pub origin: S,
+    pub direction: Unit<Vector<S>>,
+}
+
+/// A directed line segment between two points.
+#[derive(Clone, Copy, Debug)]
+pub struct Segment<S>
+where
+    S: EuclideanSpace,
+{
+    pub origin: S,
+    pub endpoint: S,
+}
+
+impl<S> ApproxEq for Plane<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: ApproxEq<Epsilon = Scalar<S>> + Float,
+{
+    type Epsilon = Scalar<S>;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::<S>::default_epsilon()
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: Self::Epsilon, max_ulps: u32) -> bool {
+        let offset = self.normal.get().dot(other.origin - self.origin);
+        if !offset.approx_eq_eps(&Scalar::<S>::zero(), eps, max_ulps) {
+            return false;
+        }
+        // The normals need only agree up to sign: `self` and `other` are
+        // the same plane whether their normals point the same way or
+        // opposite ways.
+        let alignment = self.normal.get().dot(*other.normal.get());
+        alignment.approx_eq_eps(&Scalar::<S>::one(), eps, max_ulps)
+            || (-alignment).approx_eq_eps(&Scalar::<S>::one(), eps, max_ulps)
+    }
+}
+
+/// Intersection queries between geometric primitives.
+///
+/// Implementations return the intersection's representation (a point, a
+/// line, ...) as `Output`, or `None` when the primitives do not meet.
+pub trait Intersection<T> {
+    type Output;
+
+    fn intersection(&self, other: &T) -> Option<Self::Output>;
+}
+
+impl<S> Intersection<Plane<S>> for Plane<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: InnerSpace,
+    Scalar<S>: Float,
+{
+    type Output = Line<S>;
+
+    /// The line of intersection between two planes, or `None` if they are
+    /// parallel.
+    fn intersection(&self, other: &Plane<S>) -> Option<Self::Output> {
+        let n0 = *self.normal.get();
+        let n1 = *other.normal.get();
+        let direction = n0.cross(n1);
+        if direction.magnitude() <= Scalar::<S>::epsilon() {
+            return None;
+        }
+
+        // A point on the line, found by solving the two plane equations
+        // restricted to the plane spanned by the two normals (where the
+        // system is well-determined).
+        let d0 = n0.dot(self.origin - S::origin());
+        let d1 = n1.dot(other.origin - S::origin());
+        let n00 = n0.dot(n0);
+        let n01 = n0.dot(n1);
+        let n11 = n1.dot(n1);
+        let determinant = n00 * n11 - n01 * n01;
+        let c0 = (d0 * n11 - d1 * n01) / determinant;
+        let c1 = (d1 * n00 - d0 * n01) / determinant;
+        let origin = S::origin() + n0 * c0 + n1 * c1;
+
+        Some(Line {
+            origin,
+            direction: Unit::try_from_inner(direction)?,
+        })
+    }
+}
+
+/// Computes the parameter `t` and point at which the line through `origin`
+/// in `direction` meets `plane`, or `None` if the line is parallel to the
+/// plane.
+fn plane_hit<S>(origin: S, direction: Vector<S>, plane: &Plane<S>) -> Option<(Scalar<S>, S)>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: Float,
+{
+    let denominator = direction.dot(*plane.normal.get());
+    if denominator.abs() <= Scalar::<S>::epsilon() {
+        return None;
+    }
+    let t = plane.normal.get().dot(plane.origin - origin) / denominator;
+    if !t.is_finite() {
+        return None;
+    }
+    Some((t, origin + direction * t))
+}
+
+impl<S> Intersection<Plane<S>> for Ray<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: Float,
+{
+    type Output = S;
+
+    fn intersection(&self, plane: &Plane<S>) -> Option<Self::Output> {
+        let (t, point) = plane_hit(self.origin, *self.direction.get(), plane)?;
+        if t >= Scalar::<S>::zero() {
+            Some(point)
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<S> Intersection<Plane<S>> for Segment<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: Float,
+{
+    type Output = S;
+
+    fn intersection(&self, plane: &Plane<S>) -> Option<Self::Output> {
+        let displacement = self.endpoint - self.origin;
+        let length = displacement.magnitude();
+        if length <= Scalar::<S>::epsilon() {
+            return None;
+        }
+        let direction = displacement * (Scalar::<S>::one() / length);
+        let (t, point) = plane_hit(self.origin, direction, plane)?;
+        if t >= Scalar::<S>::zero() && t <= length {
+            Some(point)
+        }
+        else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use super::*;
+    use crate::space::Basis;
+
+    type E3 = Point3<f64>;
+
+    fn xy_plane() -> Plane<E3> {
+        Plane {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 0.0),
+            normal: Unit::try_from_inner(Vector::<E3>::z()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn plane_plane_intersection() {
+        let xy = xy_plane();
+        let xz = Plane {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 0.0),
+            normal: Unit::try_from_inner(Vector::<E3>::y()).unwrap(),
+        };
+        let line = xy.intersection(&xz).unwrap();
+        assert!(line.origin.approx_eq(&EuclideanSpace::from_xyz(0.0, 0.0, 0.0)));
+        let direction = *line.direction.get();
+        assert!(direction.approx_eq(&Vector::<E3>::x()) || direction.approx_eq(&-Vector::<E3>::x()));
+    }
+
+    #[test]
+    fn plane_plane_parallel_is_none() {
+        let xy = xy_plane();
+        let offset = Plane {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::try_from_inner(Vector::<E3>::z()).unwrap(),
+        };
+        assert!(xy.intersection(&offset).is_none());
+    }
+
+    #[test]
+    fn ray_plane_forward_hit() {
+        let plane = xy_plane();
+        let ray = Ray {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 5.0),
+            direction: Unit::try_from_inner(-Vector::<E3>::z()).unwrap(),
+        };
+        let hit = ray.intersection(&plane).unwrap();
+        assert!(hit.approx_eq(&EuclideanSpace::from_xyz(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn ray_plane_parallel_is_none() {
+        let plane = xy_plane();
+        let ray = Ray {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 5.0),
+            direction: Unit::try_from_inner(Vector::<E3>::x()).unwrap(),
+        };
+        assert!(ray.intersection(&plane).is_none());
+    }
+
+    #[test]
+    fn ray_plane_behind_origin_is_none() {
+        let plane = xy_plane();
+        let ray = Ray {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 5.0),
+            direction: Unit::try_from_inner(Vector::<E3>::z()).unwrap(),
+        };
+        assert!(ray.intersection(&plane).is_none());
+    }
+
+    #[test]
+    fn segment_plane_in_range_hit() {
+        let plane = xy_plane();
+        let segment = Segment {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 5.0),
+            endpoint: EuclideanSpace::from_xyz(0.0, 0.0, -5.0),
+        };
+        let hit = segment.intersection(&plane).unwrap();
+        assert!(hit.approx_eq(&EuclideanSpace::from_xyz(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn segment_plane_past_endpoint_is_none() {
+        let plane = xy_plane();
+        let segment = Segment {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 5.0),
+            endpoint: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+        };
+        assert!(segment.intersection(&plane).is_none());
+    }
+}
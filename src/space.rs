@@ -0,0 +1,189 @@
+//! Abstractions over Euclidean vector spaces and points.
+//!
+//! This module decouples the crate's geometric types from any particular
+//! linear algebra backend (cgmath, nalgebra, mint, ...). Downstream code is
+//! written against `EuclideanSpace`, `VectorSpace`, and `FiniteDimensional`
+//! rather than against a concrete point or vector type.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::{Num, NumCast, Zero};
+use typenum::{Unsigned, U2, U3};
+
+/// A vector space over some scalar type.
+pub trait VectorSpace:
+    Add<Output = Self> + Copy + Mul<Self::Scalar, Output = Self> + Neg<Output = Self> + Sized
+    + Sub<Output = Self> + Zero
+{
+    type Scalar: Num + NumCast + PartialOrd;
+}
+
+/// A vector space with distinguished basis vectors.
+///
+/// Backends need only implement the bases their dimensionality actually
+/// has; lower-dimensional spaces simply never call `z`, and so on.
+pub trait Basis: VectorSpace {
+    fn x() -> Self;
+
+    fn y() -> Self;
+
+    fn z() -> Self;
+}
+
+/// The Euclidean inner (dot) product, and, in three dimensions, the cross
+/// product.
+pub trait InnerSpace: VectorSpace {
+    fn dot(self, other: Self) -> Self::Scalar;
+
+    fn cross(self, other: Self) -> Self;
+
+    fn magnitude(self) -> Self::Scalar
+    where
+        Self::Scalar: num::Float,
+    {
+        self.dot(self).sqrt()
+    }
+}
+
+/// A space with a known, finite number of dimensions.
+///
+/// `N` is a `typenum` unsigned integer used to select dimension-specific
+/// code paths (and to reject ill-dimensioned inputs) at compile time.
+pub trait FiniteDimensional {
+    type N: Unsigned;
+}
+
+impl<T> FiniteDimensional for (T, T) {
+    type N = U2;
+}
+
+impl<T> FiniteDimensional for (T, T, T) {
+    type N = U3;
+}
+
+/// An affine space of points over a `VectorSpace` of displacement vectors.
+pub trait EuclideanSpace:
+    Add<Self::CoordinateSpace, Output = Self> + Copy + FiniteDimensional + Sized
+    + Sub<Output = Self::CoordinateSpace>
+{
+    type CoordinateSpace: Basis;
+
+    fn origin() -> Self;
+
+    fn from_xyz(x: Scalar<Self>, y: Scalar<Self>, z: Scalar<Self>) -> Self;
+
+    /// Computes the centroid (arithmetic mean) of a set of points.
+    ///
+    /// Returns `None` if `points` is empty.
+    fn centroid<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut sum = Self::CoordinateSpace::zero();
+        let mut n: usize = 0;
+        for point in points {
+            sum = sum + (point - Self::origin());
+            n += 1;
+        }
+        if n == 0 {
+            None
+        }
+        else {
+            let f = <Scalar<Self> as NumCast>::from(1.0 / n as f64)?;
+            Some(Self::origin() + sum * f)
+        }
+    }
+
+    /// Compares two points for approximate equality by comparing their
+    /// displacement from one another against the zero vector. For
+    /// `InnerSpace` vectors this compares the displacement's magnitude
+    /// with zero; the components are not compared one by one.
+    fn approx_eq(&self, other: &Self) -> bool
+    where
+        Self::CoordinateSpace: ApproxEq,
+    {
+        (*self - *other).approx_eq(&Self::CoordinateSpace::zero())
+    }
+}
+
+/// The coordinate (displacement vector) space of a `EuclideanSpace`.
+pub type Vector<S> = <S as EuclideanSpace>::CoordinateSpace;
+
+/// The scalar type underlying a `EuclideanSpace`.
+pub type Scalar<S> = <Vector<S> as VectorSpace>::Scalar;
+
+/// Approximate equality, tolerant of the rounding error that exact
+/// comparisons (`PartialEq`) are too strict to absorb.
+///
+/// Near zero, `approx_eq` falls back to an absolute epsilon, since ULP
+/// comparisons are meaningless there (adjacent floats near zero differ by
+/// enormous relative amounts). Away from zero it compares by the integer
+/// distance between the two values' bit patterns, which tracks relative
+/// error and is stable across magnitudes.
+pub trait ApproxEq: Sized {
+    type Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon;
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, Self::default_epsilon(), Self::default_max_ulps())
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_approx_eq_for_float {
+    ($float:ty, $bits:ty, $signed:ty) => {
+        impl ApproxEq for $float {
+            type Epsilon = $float;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$float>::EPSILON * 8.0
+            }
+
+            fn approx_eq_eps(&self, other: &Self, eps: Self::Epsilon, max_ulps: u32) -> bool {
+                let (a, b) = (*self, *other);
+                if a == b {
+                    return true;
+                }
+                if a.is_nan() || b.is_nan() {
+                    return false;
+                }
+                if (a - b).abs() <= eps {
+                    return true;
+                }
+                let a = a.to_bits() as $signed;
+                let b = b.to_bits() as $signed;
+                if a.is_negative() != b.is_negative() {
+                    return false;
+                }
+                a.wrapping_sub(b).wrapping_abs() <= max_ulps as $signed
+            }
+        }
+    };
+}
+
+impl_approx_eq_for_float!(f32, u32, i32);
+impl_approx_eq_for_float!(f64, u64, i64);
+
+impl<T> ApproxEq for T
+where
+    T: InnerSpace,
+    T::Scalar: ApproxEq<Epsilon = T::Scalar> + num::Float,
+{
+    type Epsilon = T::Scalar;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::Scalar::default_epsilon()
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: Self::Epsilon, max_ulps: u32) -> bool {
+        (*self - *other)
+            .magnitude()
+            .approx_eq_eps(&T::Scalar::zero(), eps, max_ulps)
+    }
+}
@@ -0,0 +1,263 @@
+//! Discrete integer-lattice occupancy: flood fill, connected-component
+//! labeling, and exterior-surface extraction.
+//!
+//! This complements the continuous geometry in `space`/`query` with a
+//! discrete counterpart useful for mesh voxelization, occupancy grids, and
+//! topology queries. Lattice coordinates reuse the crate's `Composite`,
+//! `Converged`, and `FromItems`/`IntoItems` tuple machinery rather than
+//! introducing a dedicated coordinate type. Flood fill and
+//! connected-component labeling support both axis (6-connected in 3D) and
+//! full (26-connected in 3D) `Connectivity`.
+
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::space::FiniteDimensional;
+use crate::{Composite, Converged, FromItems, IntoItems};
+
+/// A set of occupied integer lattice coordinates.
+#[derive(Clone, Debug)]
+pub struct Voxels<S> {
+    occupied: HashSet<S>,
+}
+
+/// Neighbor connectivity for flood fill and connected-component labeling.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Connectivity {
+    /// The `2 * N` axis-aligned neighbors (6-connected in 3D).
+    Axis,
+    /// All `3^N - 1` neighbors, including diagonals (26-connected in 3D).
+    Full,
+}
+
+impl<S> Voxels<S>
+where
+    S: Composite<Item = i64> + Converged + Copy + Eq + FiniteDimensional + FromItems + Hash
+        + IntoItems,
+{
+    pub fn new() -> Self {
+        Voxels {
+            occupied: HashSet::new(),
+        }
+    }
+
+    /// The lattice origin, `(0, 0, ...)`.
+    pub fn origin() -> S {
+        S::converged(0)
+    }
+
+    pub fn insert(&mut self, coordinate: S) -> bool {
+        self.occupied.insert(coordinate)
+    }
+
+    pub fn remove(&mut self, coordinate: &S) -> bool {
+        self.occupied.remove(coordinate)
+    }
+
+    pub fn contains(&self, coordinate: &S) -> bool {
+        self.occupied.contains(coordinate)
+    }
+
+    pub fn len(&self) -> usize {
+        self.occupied.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.occupied.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &S> {
+        self.occupied.iter()
+    }
+
+    /// The `2 * N` axis-aligned neighbors of `coordinate`.
+    pub fn neighbors(coordinate: S) -> Vec<S> {
+        Self::neighbors_with(coordinate, Connectivity::Axis)
+    }
+
+    /// The neighbors of `coordinate` under `connectivity`: the `2 * N`
+    /// axis-aligned neighbors, or all `3^N - 1` neighbors including
+    /// diagonals.
+    pub fn neighbors_with(coordinate: S, connectivity: Connectivity) -> Vec<S> {
+        let components: Vec<i64> = coordinate.into_items().into_iter().collect();
+        match connectivity {
+            Connectivity::Axis => {
+                let mut neighbors = Vec::with_capacity(2 * components.len());
+                for axis in 0..components.len() {
+                    for delta in [-1i64, 1] {
+                        let mut shifted = components.clone();
+                        shifted[axis] += delta;
+                        if let Some(neighbor) = S::from_items(shifted) {
+                            neighbors.push(neighbor);
+                        }
+                    }
+                }
+                neighbors
+            }
+            Connectivity::Full => {
+                let mut offsets = vec![Vec::new()];
+                for _ in 0..components.len() {
+                    offsets = offsets
+                        .into_iter()
+                        .flat_map(|prefix| {
+                            [-1i64, 0, 1].into_iter().map(move |delta| {
+                                let mut prefix = prefix.clone();
+                                prefix.push(delta);
+                                prefix
+                            })
+                        })
+                        .collect();
+                }
+                offsets
+                    .into_iter()
+                    .filter(|offset| offset.iter().any(|&delta| delta != 0))
+                    .filter_map(|offset| {
+                        let shifted: Vec<i64> = components
+                            .iter()
+                            .zip(offset.iter())
+                            .map(|(&c, &d)| c + d)
+                            .collect();
+                        S::from_items(shifted)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Labels the occupied coordinates into 6-connected (axis) components
+    /// via iterative breadth-first flood fill.
+    pub fn connected_components(&self) -> Vec<Vec<S>> {
+        self.connected_components_with(Connectivity::Axis)
+    }
+
+    /// Labels the occupied coordinates into components under
+    /// `connectivity` via iterative breadth-first flood fill.
+    pub fn connected_components_with(&self, connectivity: Connectivity) -> Vec<Vec<S>> {
+        let mut visited = HashSet::new();
+        let mut components = Vec::new();
+
+        for &seed in &self.occupied {
+            if visited.contains(&seed) {
+                continue;
+            }
+            let mut component = Vec::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(seed);
+            visited.insert(seed);
+            while let Some(coordinate) = queue.pop_front() {
+                component.push(coordinate);
+                for neighbor in Self::neighbors_with(coordinate, connectivity) {
+                    if self.occupied.contains(&neighbor) && visited.insert(neighbor) {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// The total surface face count: for each occupied cell, the faces
+    /// whose opposite neighbor is unoccupied. Interior cavities are
+    /// counted as surface; see `exterior_surface_area` to exclude them.
+    pub fn surface_area(&self) -> usize {
+        self.occupied
+            .iter()
+            .map(|&coordinate| {
+                Self::neighbors(coordinate)
+                    .into_iter()
+                    .filter(|neighbor| !self.occupied.contains(neighbor))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The exterior face count, excluding faces that border an interior
+    /// cavity.
+    ///
+    /// This flood-fills the empty complement of the occupied set from a
+    /// seed just outside its bounding box, then counts only the empty
+    /// faces reachable from that seed.
+    pub fn exterior_surface_area(&self) -> usize {
+        let reachable = self.reachable_from_outside();
+        self.occupied
+            .iter()
+            .map(|&coordinate| {
+                Self::neighbors(coordinate)
+                    .into_iter()
+                    .filter(|neighbor| {
+                        !self.occupied.contains(neighbor) && reachable.contains(neighbor)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    fn reachable_from_outside(&self) -> HashSet<S> {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return HashSet::new(),
+        };
+        let min: Vec<i64> = min.into_items().into_iter().collect();
+        let max: Vec<i64> = max.into_items().into_iter().collect();
+
+        let mut seed = min.clone();
+        seed[0] -= 1;
+        let seed = match S::from_items(seed) {
+            Some(seed) => seed,
+            None => return HashSet::new(),
+        };
+
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(seed);
+        queue.push_back(seed);
+
+        while let Some(coordinate) = queue.pop_front() {
+            for neighbor in Self::neighbors(coordinate) {
+                if self.occupied.contains(&neighbor) || visited.contains(&neighbor) {
+                    continue;
+                }
+                let components: Vec<i64> = neighbor.into_items().into_iter().collect();
+                let within_exterior_shell = components
+                    .iter()
+                    .enumerate()
+                    .all(|(i, &value)| value >= min[i] - 1 && value <= max[i] + 1);
+                if within_exterior_shell {
+                    visited.insert(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        visited
+    }
+
+    /// The componentwise minimum and maximum occupied coordinates.
+    fn bounds(&self) -> Option<(S, S)> {
+        let mut occupied = self.occupied.iter();
+        let first: Vec<i64> = (*occupied.next()?).into_items().into_iter().collect();
+        let mut min = first.clone();
+        let mut max = first;
+        for &coordinate in occupied {
+            for (i, value) in coordinate.into_items().into_iter().enumerate() {
+                if value < min[i] {
+                    min[i] = value;
+                }
+                if value > max[i] {
+                    max[i] = value;
+                }
+            }
+        }
+        Some((S::from_items(min)?, S::from_items(max)?))
+    }
+}
+
+impl<S> Default for Voxels<S>
+where
+    S: Composite<Item = i64> + Converged + Copy + Eq + FiniteDimensional + FromItems + Hash
+        + IntoItems,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}